@@ -98,6 +98,67 @@ impl Grid<u8> {
 }
 
 impl<V> Grid<V> {
+    /// Creates a new grid of the given dimensions from a flat, row-major list of values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width * height != values.len()`, or if exactly one of `width`/`height` is zero.
+    pub fn new(width: usize, height: usize, values: Vec<V>) -> Self {
+        assert!(
+            (width == 0) == (height == 0),
+            "a grid cannot have a zero width and a non-zero height, or vice versa"
+        );
+        assert_eq!(
+            width * height,
+            values.len(),
+            "the number of values does not match the grid dimensions"
+        );
+
+        Self {
+            values,
+            width,
+            height,
+        }
+    }
+
+    /// Creates a new grid of the given dimensions, filling every cell with a clone of `value`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if exactly one of `width`/`height` is zero.
+    pub fn filled(width: usize, height: usize, value: V) -> Self
+    where
+        V: Clone,
+    {
+        assert!(
+            (width == 0) == (height == 0),
+            "a grid cannot have a zero width and a non-zero height, or vice versa"
+        );
+
+        Self::new(width, height, vec![value; width * height])
+    }
+
+    /// Converts a [`Grid<U>`](Grid) into a `Grid<V>` by converting every element with
+    /// [`V::from`](From::from).
+    ///
+    /// ```
+    /// # use aoclib::Grid;
+    /// let digits = Grid::parse(b"12\n34\n").map(|b| b - b'0');
+    /// let digits: Grid<u32> = Grid::from_grid(digits);
+    ///
+    /// assert_eq!(*digits.get(1, 1), 4);
+    /// ```
+    pub fn from_grid<U>(other: Grid<U>) -> Self
+    where
+        V: From<U>,
+    {
+        Self {
+            values: other.values.into_iter().map(V::from).collect(),
+            width: other.width,
+            height: other.height,
+        }
+    }
+
     /// Returns the width of this grid.
     pub fn width(&self) -> usize {
         self.width
@@ -206,6 +267,40 @@ impl<V> Grid<V> {
         }
     }
 
+    /// Renders the grid into a human-readable, column-aligned table.
+    ///
+    /// Cells are separated by a single space and rows by `'\n'`. Every cell is right-padded with
+    /// spaces to the width of the widest rendered cell in the grid, so that columns line up.
+    ///
+    /// ```
+    /// # use aoclib::Grid;
+    /// let grid = Grid::new(3, 2, vec![1, 20, 3, 400, 5, 6]);
+    ///
+    /// assert_eq!(grid.to_pretty_string(), "1   20  3  \n400 5   6  \n");
+    /// ```
+    pub fn to_pretty_string(&self) -> String
+    where
+        V: std::fmt::Display,
+    {
+        use std::fmt::Write;
+
+        let rendered: Vec<String> = self.values.iter().map(|value| value.to_string()).collect();
+        let width = rendered.iter().map(|s| s.chars().count()).max().unwrap_or(0);
+
+        let mut output = String::new();
+        for row in rendered.chunks(self.width) {
+            for (col, cell) in row.iter().enumerate() {
+                if col > 0 {
+                    output.push(' ');
+                }
+                write!(output, "{cell:width$}").expect("writing to a String never fails");
+            }
+            output.push('\n');
+        }
+
+        output
+    }
+
     /// Returns an [`Iterator`] over all rows of this grid.
     pub fn rows(&self) -> Rows<V> {
         Rows { row: 0, grid: self }
@@ -215,6 +310,205 @@ impl<V> Grid<V> {
     pub fn cols(&self) -> Cols<V> {
         Cols { col: 0, grid: self }
     }
+
+    /// Returns an [`Iterator`] over every element of this grid, left-to-right, top-to-bottom,
+    /// together with its coordinates.
+    pub fn cells(&self) -> Cells<V> {
+        Cells {
+            grid: self,
+            front: 0,
+            back: self.values.len(),
+        }
+    }
+
+    /// Copies the rectangle starting at `(col_start, row_start)` with the given `width` and
+    /// `height` into a new, owned [`Grid`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the requested rectangle extends past the right or bottom edge of this grid.
+    pub fn subgrid(&self, col_start: usize, row_start: usize, width: usize, height: usize) -> Self
+    where
+        V: Clone,
+    {
+        assert!(col_start + width <= self.width);
+        assert!(row_start + height <= self.height);
+
+        let mut values = Vec::with_capacity(width * height);
+        for y in row_start..(row_start + height) {
+            for x in col_start..(col_start + width) {
+                values.push(self.get(x, y).clone());
+            }
+        }
+
+        Self {
+            values,
+            width,
+            height,
+        }
+    }
+
+    /// Returns an [`Iterator`] over every aligned `win_w × win_h` window of this grid, without
+    /// allocating.
+    ///
+    /// Windows are yielded left-to-right, top-to-bottom by their top-left corner.
+    pub fn windows(&self, win_w: usize, win_h: usize) -> Windows<V> {
+        Windows {
+            grid: self,
+            win_w,
+            win_h,
+            col: 0,
+            row: 0,
+        }
+    }
+
+    /// Returns an [`Iterator`] over the up to 4 orthogonal neighbors of `(x, y)`, together with
+    /// the value stored at each.
+    ///
+    /// Neighbors that would fall outside the grid (e.g. for cells on the border) are silently
+    /// omitted.
+    pub fn neighbors4(&self, x: usize, y: usize) -> impl Iterator<Item = ((usize, usize), &V)> {
+        self.neighbor_coords4(x, y)
+            .map(move |(nx, ny)| ((nx, ny), self.get(nx, ny)))
+    }
+
+    /// Returns an [`Iterator`] over the up to 8 orthogonal and diagonal neighbors of `(x, y)`,
+    /// together with the value stored at each.
+    ///
+    /// Neighbors that would fall outside the grid (e.g. for cells on the border or in a corner)
+    /// are silently omitted.
+    pub fn neighbors8(&self, x: usize, y: usize) -> impl Iterator<Item = ((usize, usize), &V)> {
+        self.neighbor_coords8(x, y)
+            .map(move |(nx, ny)| ((nx, ny), self.get(nx, ny)))
+    }
+
+    /// Like [`Grid::neighbors4()`], but yields only the coordinates, without borrowing `self`.
+    pub fn neighbor_coords4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> {
+        self.neighbor_coords(x, y, &NEIGHBOR_OFFSETS4)
+    }
+
+    /// Like [`Grid::neighbors8()`], but yields only the coordinates, without borrowing `self`.
+    pub fn neighbor_coords8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> {
+        self.neighbor_coords(x, y, &NEIGHBOR_OFFSETS8)
+    }
+
+    fn neighbor_coords(
+        &self,
+        x: usize,
+        y: usize,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width as isize;
+        let height = self.height as isize;
+
+        offsets.iter().filter_map(move |(dx, dy)| {
+            let nx = x as isize + dx;
+            let ny = y as isize + dy;
+
+            if nx >= 0 && nx < width && ny >= 0 && ny < height {
+                Some((nx as usize, ny as usize))
+            } else {
+                None
+            }
+        })
+    }
+}
+
+const NEIGHBOR_OFFSETS4: [(isize, isize); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+
+const NEIGHBOR_OFFSETS8: [(isize, isize); 8] = [
+    (-1, 0),
+    (1, 0),
+    (0, -1),
+    (0, 1),
+    (-1, -1),
+    (-1, 1),
+    (1, -1),
+    (1, 1),
+];
+
+/// A non-owning view into a rectangular region of a [`Grid`], yielded by [`Grid::windows()`].
+#[derive(Clone)]
+pub struct GridWindow<'a, V> {
+    grid: &'a Grid<V>,
+    col_start: usize,
+    row_start: usize,
+    width: usize,
+    height: usize,
+}
+
+impl<'a, V> GridWindow<'a, V> {
+    /// Returns the width of this window.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the height of this window.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the column of the underlying grid at which this window starts.
+    pub fn col_start(&self) -> usize {
+        self.col_start
+    }
+
+    /// Returns the row of the underlying grid at which this window starts.
+    pub fn row_start(&self) -> usize {
+        self.row_start
+    }
+
+    /// Gets a reference to the element at column `x` and row `y`, relative to this window.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the indices is out of range for this window.
+    pub fn get(&self, x: usize, y: usize) -> &'a V {
+        assert!(x < self.width);
+        assert!(y < self.height);
+
+        self.grid.get(self.col_start + x, self.row_start + y)
+    }
+}
+
+/// An [`Iterator`] over all aligned windows of a [`Grid`], see [`Grid::windows()`].
+#[derive(Clone)]
+pub struct Windows<'a, V> {
+    grid: &'a Grid<V>,
+    win_w: usize,
+    win_h: usize,
+    col: usize,
+    row: usize,
+}
+
+impl<'a, V> Iterator for Windows<'a, V> {
+    type Item = GridWindow<'a, V>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.win_w > self.grid.width || self.win_h > self.grid.height {
+            return None;
+        }
+
+        if self.row + self.win_h > self.grid.height {
+            return None;
+        }
+
+        let window = GridWindow {
+            grid: self.grid,
+            col_start: self.col,
+            row_start: self.row,
+            width: self.win_w,
+            height: self.win_h,
+        };
+
+        self.col += 1;
+        if self.col + self.win_w > self.grid.width {
+            self.col = 0;
+            self.row += 1;
+        }
+
+        Some(window)
+    }
 }
 
 #[derive(Clone)]
@@ -239,7 +533,8 @@ impl<'a, V> IntoIterator for GridRow<'a, V> {
         GridRowIter {
             grid: self.grid,
             row: self.row,
-            col: 0,
+            front: 0,
+            back: self.grid.width,
         }
     }
 }
@@ -265,52 +560,101 @@ impl<'a, V> IntoIterator for GridCol<'a, V> {
     fn into_iter(self) -> Self::IntoIter {
         GridColIter {
             grid: self.grid,
-            row: 0,
             col: self.col,
+            front: 0,
+            back: self.grid.height,
         }
     }
 }
 
+/// An [`Iterator`] over the elements of a single row of a [`Grid`], see [`GridRow`].
 #[derive(Clone)]
 pub struct GridRowIter<'a, V> {
     grid: &'a Grid<V>,
     row: usize,
-    col: usize,
+    front: usize,
+    back: usize,
 }
 
 impl<'a, V> Iterator for GridRowIter<'a, V> {
     type Item = &'a V;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.col < self.grid.width {
-            let val = self.grid.get(self.col, self.row);
-            self.col += 1;
+        if self.front < self.back {
+            let val = self.grid.get(self.front, self.row);
+            self.front += 1;
             Some(val)
         } else {
             None
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, V> DoubleEndedIterator for GridRowIter<'a, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            self.back -= 1;
+            Some(self.grid.get(self.back, self.row))
+        } else {
+            None
+        }
+    }
 }
 
+impl<'a, V> ExactSizeIterator for GridRowIter<'a, V> {
+    fn len(&self) -> usize {
+        self.back - self.front
+    }
+}
+
+/// An [`Iterator`] over the elements of a single column of a [`Grid`], see [`GridCol`].
 #[derive(Clone)]
 pub struct GridColIter<'a, V> {
     grid: &'a Grid<V>,
-    row: usize,
     col: usize,
+    front: usize,
+    back: usize,
 }
 
 impl<'a, V> Iterator for GridColIter<'a, V> {
     type Item = &'a V;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.row < self.grid.height {
-            let val = self.grid.get(self.col, self.row);
-            self.row += 1;
+        if self.front < self.back {
+            let val = self.grid.get(self.col, self.front);
+            self.front += 1;
             Some(val)
         } else {
             None
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, V> DoubleEndedIterator for GridColIter<'a, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            self.back -= 1;
+            Some(self.grid.get(self.col, self.back))
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, V> ExactSizeIterator for GridColIter<'a, V> {
+    fn len(&self) -> usize {
+        self.back - self.front
+    }
 }
 
 #[derive(Clone)]
@@ -353,6 +697,52 @@ impl<'a, V> Iterator for Cols<'a, V> {
     }
 }
 
+/// An [`Iterator`] over every element of a [`Grid`], see [`Grid::cells()`].
+#[derive(Clone)]
+pub struct Cells<'a, V> {
+    grid: &'a Grid<V>,
+    front: usize,
+    back: usize,
+}
+
+impl<'a, V> Iterator for Cells<'a, V> {
+    type Item = ((usize, usize), &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            let coords = self.grid.slice_index_to_coords(self.front);
+            let val = (coords, &self.grid.values[self.front]);
+            self.front += 1;
+            Some(val)
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, V> DoubleEndedIterator for Cells<'a, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            self.back -= 1;
+            let coords = self.grid.slice_index_to_coords(self.back);
+            Some((coords, &self.grid.values[self.back]))
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, V> ExactSizeIterator for Cells<'a, V> {
+    fn len(&self) -> usize {
+        self.back - self.front
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::Grid;
@@ -484,6 +874,207 @@ mod tests {
         }
     }
 
+    #[test]
+    fn new() {
+        let grid = Grid::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+
+        assert_eq!(grid.width(), 2);
+        assert_eq!(grid.height(), 3);
+        assert_eq!(*grid.get(1, 2), 6);
+    }
+
+    #[test]
+    #[should_panic]
+    fn new_wrong_value_count() {
+        Grid::new(2, 3, vec![1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn new_zero_width_non_zero_height() {
+        Grid::<u8>::new(0, 3, vec![]);
+    }
+
+    #[test]
+    fn filled() {
+        let grid = Grid::filled(3, 2, 'x');
+
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert!(grid.rows().all(|row| row.into_iter().all(|c| *c == 'x')));
+    }
+
+    #[test]
+    fn from_grid() {
+        let bytes = Grid::parse(b"12\n34\n");
+        let digits: Grid<u32> = Grid::from_grid(bytes.map(|b| (b - b'0') as u32));
+
+        assert_eq!(*digits.get(0, 0), 1);
+        assert_eq!(*digits.get(1, 1), 4);
+    }
+
+    #[test]
+    fn subgrid() {
+        let grid = Grid::parse(SMILEY_GRID);
+
+        let eyes = grid.subgrid(2, 1, 8, 1);
+
+        assert_eq!(eyes.width(), 8);
+        assert_eq!(eyes.height(), 1);
+        let row: Vec<_> = eyes.row(0).into_iter().copied().collect();
+        assert_eq!(row, b"XX....XX");
+    }
+
+    #[test]
+    #[should_panic]
+    fn subgrid_out_of_bounds() {
+        let grid = Grid::parse(SMILEY_GRID);
+
+        grid.subgrid(10, 0, 3, 1);
+    }
+
+    #[test]
+    fn windows() {
+        let grid = Grid::parse(b"ab\ncd");
+
+        let windows: Vec<_> = grid
+            .windows(1, 1)
+            .map(|window| *window.get(0, 0))
+            .collect();
+
+        assert_eq!(windows, b"abcd");
+    }
+
+    #[test]
+    fn windows_positions() {
+        let grid = Grid::parse(b"abc\ndef");
+
+        let positions: Vec<_> = grid
+            .windows(2, 2)
+            .map(|window| (window.col_start(), window.row_start()))
+            .collect();
+
+        assert_eq!(positions, [(0, 0), (1, 0)]);
+    }
+
+    #[test]
+    fn windows_larger_than_grid() {
+        let grid = Grid::parse(b"ab\ncd");
+
+        assert_eq!(grid.windows(3, 1).count(), 0);
+        assert_eq!(grid.windows(1, 3).count(), 0);
+    }
+
+    #[test]
+    fn neighbors4_center() {
+        let grid = Grid::parse(b"abc\ndef\nghi");
+
+        let mut neighbors: Vec<_> = grid.neighbors4(1, 1).map(|(_, v)| *v).collect();
+        neighbors.sort();
+
+        assert_eq!(neighbors, b"bdfh");
+    }
+
+    #[test]
+    fn neighbors4_corner() {
+        let grid = Grid::parse(b"abc\ndef\nghi");
+
+        let neighbors: Vec<_> = grid.neighbors4(0, 0).map(|(coord, v)| (coord, *v)).collect();
+
+        assert_eq!(neighbors, [((1, 0), b'b'), ((0, 1), b'd')]);
+    }
+
+    #[test]
+    fn neighbors8_corner() {
+        let grid = Grid::parse(b"abc\ndef\nghi");
+
+        let mut neighbors: Vec<_> = grid.neighbors8(2, 2).map(|(_, v)| *v).collect();
+        neighbors.sort();
+
+        assert_eq!(neighbors, b"efh");
+    }
+
+    #[test]
+    fn neighbor_coords4_no_value_borrow() {
+        let grid = Grid::parse(b"ab\ncd");
+
+        let mut coords: Vec<_> = grid.neighbor_coords4(1, 1).collect();
+        coords.sort();
+
+        assert_eq!(coords, [(0, 1), (1, 0)]);
+    }
+
+    #[test]
+    fn row_rev() {
+        let grid = Grid::parse(b"abc\ndef");
+
+        let row: Vec<_> = grid.row(0).into_iter().rev().copied().collect();
+
+        assert_eq!(row, b"cba");
+    }
+
+    #[test]
+    fn col_rev() {
+        let grid = Grid::parse(b"ab\ncd\nef");
+
+        let col: Vec<_> = grid.col(0).into_iter().rev().copied().collect();
+
+        assert_eq!(col, b"eca");
+    }
+
+    #[test]
+    fn row_iter_len() {
+        let grid = Grid::parse(b"abc\ndef");
+
+        let mut iter = grid.row(0).into_iter();
+        assert_eq!(iter.len(), 3);
+        iter.next();
+        assert_eq!(iter.len(), 2);
+        iter.next_back();
+        assert_eq!(iter.len(), 1);
+    }
+
+    #[test]
+    fn cells() {
+        let grid = Grid::parse(b"ab\ncd");
+
+        let cells: Vec<_> = grid.cells().map(|(coord, v)| (coord, *v)).collect();
+
+        assert_eq!(
+            cells,
+            [
+                ((0, 0), b'a'),
+                ((1, 0), b'b'),
+                ((0, 1), b'c'),
+                ((1, 1), b'd'),
+            ]
+        );
+    }
+
+    #[test]
+    fn cells_rev() {
+        let grid = Grid::parse(b"ab\ncd");
+
+        let cells: Vec<_> = grid.cells().rev().map(|(coord, v)| (coord, *v)).collect();
+
+        assert_eq!(
+            cells,
+            [
+                ((1, 1), b'd'),
+                ((0, 1), b'c'),
+                ((1, 0), b'b'),
+                ((0, 0), b'a'),
+            ]
+        );
+    }
+
+    #[test]
+    fn to_pretty_string() {
+        let grid = Grid::new(3, 2, vec![1, 20, 3, 400, 5, 6]);
+
+        assert_eq!(grid.to_pretty_string(), "1   20  3  \n400 5   6  \n");
+    }
+
     #[test]
     #[should_panic]
     fn parse_invalid_grid() {